@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use derive_more::{Display, From};
+
+use protocol::{ProtocolError, ProtocolErrorKind, ProtocolResult};
+
+/// The fixed cycles cost of every billable action a native contract can
+/// perform. Each variant is consumed once per call through
+/// [`consume_cycles`].
+#[derive(Debug, Clone, Copy)]
+pub enum CyclesAction {
+    BankRegister,
+    BankTransfer,
+    BankMint,
+    BankBurn,
+    BankApprove,
+    BankTransferFrom,
+    BankLock,
+    BankRelease,
+}
+
+impl CyclesAction {
+    fn cycles(self) -> u64 {
+        match self {
+            CyclesAction::BankRegister => 10_000,
+            CyclesAction::BankTransfer => 3_000,
+            CyclesAction::BankMint => 3_000,
+            CyclesAction::BankBurn => 3_000,
+            CyclesAction::BankApprove => 2_000,
+            CyclesAction::BankTransferFrom => 4_000,
+            CyclesAction::BankLock => 5_000,
+            CyclesAction::BankRelease => 5_000,
+        }
+    }
+}
+
+/// Charge the cycles cost of `action` (scaled by `price`) against `used`,
+/// rejecting the call if doing so would exceed `limit`.
+pub fn consume_cycles(
+    action: CyclesAction,
+    price: u64,
+    used: &mut u64,
+    limit: &u64,
+) -> ProtocolResult<()> {
+    let cost = action.cycles().saturating_mul(price);
+    let new_used = used.saturating_add(cost);
+
+    if &new_used > limit {
+        return Err(CyclesError::NotEnoughCycles.into());
+    }
+
+    *used = new_used;
+    Ok(())
+}
+
+#[derive(Debug, Display, From)]
+pub enum CyclesError {
+    #[display(fmt = "cycles not enough")]
+    NotEnoughCycles,
+}
+
+impl Error for CyclesError {}
+
+impl From<CyclesError> for ProtocolError {
+    fn from(err: CyclesError) -> ProtocolError {
+        ProtocolError::new(ProtocolErrorKind::Executor, Box::new(err))
+    }
+}
@@ -0,0 +1,496 @@
+use bytes::Bytes;
+use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+use protocol::fixed_codec::{impl_default_fixed_codec_for, FixedCodec, FixedCodecError};
+use protocol::traits::executor::contract::StoreSchema;
+use protocol::types::{Asset, AssetID, Balance, ContractAddress, Hash};
+use protocol::ProtocolResult;
+
+/// Key wrapper for `FixedAssetSchema`, so the raw `AssetID` hash can be
+/// `FixedCodec`-encoded without entangling `protocol::types::Asset` with
+/// storage concerns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedAssetID(AssetID);
+
+impl FixedAssetID {
+    pub fn new(id: AssetID) -> Self {
+        FixedAssetID(id)
+    }
+}
+
+impl From<FixedAssetID> for AssetID {
+    fn from(fixed: FixedAssetID) -> AssetID {
+        fixed.0
+    }
+}
+
+impl Encodable for FixedAssetID {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(1).append(&self.0);
+    }
+}
+
+impl Decodable for FixedAssetID {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedAssetID(r.val_at(0)?))
+    }
+}
+
+/// Whether an asset is a divisible, interchangeable token or a unique,
+/// indivisible one, and with what precision fungible amounts are displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetKind {
+    Fungible { decimals: u8 },
+    NonFungible,
+}
+
+/// The declared, immutable rules a registered asset is bound by. Set once at
+/// `register` time and consulted by every balance-moving operation so
+/// downstream contracts can trust the invariants without re-checking them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetSchema {
+    pub kind: AssetKind,
+    pub mintable: bool,
+    pub fixed_supply: bool,
+}
+
+impl Encodable for AssetSchema {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        let (kind_tag, decimals) = match self.kind {
+            AssetKind::Fungible { decimals } => (0u8, decimals),
+            AssetKind::NonFungible => (1u8, 0u8),
+        };
+        s.begin_list(4)
+            .append(&kind_tag)
+            .append(&decimals)
+            .append(&(self.mintable as u8))
+            .append(&(self.fixed_supply as u8));
+    }
+}
+
+impl Decodable for AssetSchema {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        let kind_tag: u8 = r.val_at(0)?;
+        let decimals: u8 = r.val_at(1)?;
+        let mintable: u8 = r.val_at(2)?;
+        let fixed_supply: u8 = r.val_at(3)?;
+
+        let kind = match kind_tag {
+            0 => AssetKind::Fungible { decimals },
+            1 => AssetKind::NonFungible,
+            _ => return Err(DecoderError::Custom("invalid asset kind tag")),
+        };
+
+        Ok(AssetSchema {
+            kind,
+            mintable: mintable != 0,
+            fixed_supply: fixed_supply != 0,
+        })
+    }
+}
+
+/// Value wrapper for `FixedAssetSchema`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedAsset {
+    pub inner: Asset,
+    pub schema: AssetSchema,
+}
+
+impl FixedAsset {
+    pub fn new(inner: Asset, schema: AssetSchema) -> Self {
+        FixedAsset { inner, schema }
+    }
+}
+
+impl Encodable for FixedAsset {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(2).append(&self.inner).append(&self.schema);
+    }
+}
+
+impl Decodable for FixedAsset {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedAsset {
+            inner: r.val_at(0)?,
+            schema: r.val_at(1)?,
+        })
+    }
+}
+
+impl_default_fixed_codec_for!(FixedAssetID);
+impl_default_fixed_codec_for!(FixedAsset);
+
+/// `AssetID -> Asset` registry. Populated by `NativeBankContract::register`.
+pub struct FixedAssetSchema;
+
+impl StoreSchema for FixedAssetSchema {
+    type Key = FixedAssetID;
+    type Value = FixedAsset;
+}
+
+/// Key for `FixedBalanceSchema`: a holder's balance of one asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBalanceID {
+    asset_id: AssetID,
+    account: ContractAddress,
+}
+
+impl FixedBalanceID {
+    pub fn new(asset_id: AssetID, account: ContractAddress) -> Self {
+        FixedBalanceID { asset_id, account }
+    }
+}
+
+impl Encodable for FixedBalanceID {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(2).append(&self.asset_id).append(&self.account);
+    }
+}
+
+impl Decodable for FixedBalanceID {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedBalanceID {
+            asset_id: r.val_at(0)?,
+            account: r.val_at(1)?,
+        })
+    }
+}
+
+/// Value wrapper for `FixedBalanceSchema`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBalance(pub Balance);
+
+impl FixedBalance {
+    pub fn new(balance: Balance) -> Self {
+        FixedBalance(balance)
+    }
+}
+
+impl Encodable for FixedBalance {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(1).append(&self.0);
+    }
+}
+
+impl Decodable for FixedBalance {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedBalance(r.val_at(0)?))
+    }
+}
+
+impl_default_fixed_codec_for!(FixedBalanceID);
+impl_default_fixed_codec_for!(FixedBalance);
+
+/// `(AssetID, account) -> Balance` holder ledger. Credited in full to the
+/// issuing contract on `register`, then moved by `transfer`/`mint`/`burn`.
+pub struct FixedBalanceSchema;
+
+impl StoreSchema for FixedBalanceSchema {
+    type Key = FixedBalanceID;
+    type Value = FixedBalance;
+}
+
+/// Key for `FixedAllowanceSchema`: how much `spender` may move out of
+/// `owner`'s balance of one asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedAllowanceID {
+    asset_id: AssetID,
+    owner: ContractAddress,
+    spender: ContractAddress,
+}
+
+impl FixedAllowanceID {
+    pub fn new(asset_id: AssetID, owner: ContractAddress, spender: ContractAddress) -> Self {
+        FixedAllowanceID {
+            asset_id,
+            owner,
+            spender,
+        }
+    }
+}
+
+impl Encodable for FixedAllowanceID {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(3)
+            .append(&self.asset_id)
+            .append(&self.owner)
+            .append(&self.spender);
+    }
+}
+
+impl Decodable for FixedAllowanceID {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedAllowanceID {
+            asset_id: r.val_at(0)?,
+            owner: r.val_at(1)?,
+            spender: r.val_at(2)?,
+        })
+    }
+}
+
+/// Value wrapper for `FixedAllowanceSchema`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedAllowance(pub Balance);
+
+impl FixedAllowance {
+    pub fn new(balance: Balance) -> Self {
+        FixedAllowance(balance)
+    }
+}
+
+impl Encodable for FixedAllowance {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(1).append(&self.0);
+    }
+}
+
+impl Decodable for FixedAllowance {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedAllowance(r.val_at(0)?))
+    }
+}
+
+impl_default_fixed_codec_for!(FixedAllowanceID);
+impl_default_fixed_codec_for!(FixedAllowance);
+
+/// `(AssetID, owner, spender) -> Balance` delegated-spending ledger. Set by
+/// `approve`, consumed (and decremented) by `transfer_from`.
+pub struct FixedAllowanceSchema;
+
+impl StoreSchema for FixedAllowanceSchema {
+    type Key = FixedAllowanceID;
+    type Value = FixedAllowance;
+}
+
+/// A relayer-facing record of one `lock` call, queryable through
+/// `BankContract::get_lock_receipt` so the other chain can verify the
+/// escrow before minting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockReceipt {
+    pub asset_id: AssetID,
+    pub amount: Balance,
+    pub target_chain_id: Hash,
+    pub recipient: Bytes,
+    pub nonce: u64,
+}
+
+/// Key for `FixedLockSchema`: the total amount of one asset currently
+/// escrowed by `lock` and not yet `release`d.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedLockID(AssetID);
+
+impl FixedLockID {
+    pub fn new(asset_id: AssetID) -> Self {
+        FixedLockID(asset_id)
+    }
+}
+
+impl Encodable for FixedLockID {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(1).append(&self.0);
+    }
+}
+
+impl Decodable for FixedLockID {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedLockID(r.val_at(0)?))
+    }
+}
+
+/// Value wrapper for `FixedLockSchema`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedLock(pub Balance);
+
+impl FixedLock {
+    pub fn new(balance: Balance) -> Self {
+        FixedLock(balance)
+    }
+}
+
+impl Encodable for FixedLock {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(1).append(&self.0);
+    }
+}
+
+impl Decodable for FixedLock {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedLock(r.val_at(0)?))
+    }
+}
+
+impl_default_fixed_codec_for!(FixedLockID);
+impl_default_fixed_codec_for!(FixedLock);
+
+/// `AssetID -> Balance` per-asset escrow total. Credited by `lock`, debited
+/// by `release`.
+pub struct FixedLockSchema;
+
+impl StoreSchema for FixedLockSchema {
+    type Key = FixedLockID;
+    type Value = FixedLock;
+}
+
+/// Key for `FixedLockReceiptSchema`: one asset's lock receipts, addressed by
+/// the nonce the caller passed to `lock`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedLockReceiptID {
+    asset_id: AssetID,
+    nonce: u64,
+}
+
+impl FixedLockReceiptID {
+    pub fn new(asset_id: AssetID, nonce: u64) -> Self {
+        FixedLockReceiptID { asset_id, nonce }
+    }
+}
+
+impl Encodable for FixedLockReceiptID {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(2).append(&self.asset_id).append(&self.nonce);
+    }
+}
+
+impl Decodable for FixedLockReceiptID {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedLockReceiptID {
+            asset_id: r.val_at(0)?,
+            nonce: r.val_at(1)?,
+        })
+    }
+}
+
+/// Value wrapper for `FixedLockReceiptSchema`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedLockReceipt(pub LockReceipt);
+
+impl FixedLockReceipt {
+    pub fn new(receipt: LockReceipt) -> Self {
+        FixedLockReceipt(receipt)
+    }
+}
+
+impl Encodable for FixedLockReceipt {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(5)
+            .append(&self.0.asset_id)
+            .append(&self.0.amount)
+            .append(&self.0.target_chain_id)
+            .append(&self.0.recipient.to_vec())
+            .append(&self.0.nonce);
+    }
+}
+
+impl Decodable for FixedLockReceipt {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedLockReceipt(LockReceipt {
+            asset_id: r.val_at(0)?,
+            amount: r.val_at(1)?,
+            target_chain_id: r.val_at(2)?,
+            recipient: Bytes::from(r.val_at::<Vec<u8>>(3)?),
+            nonce: r.val_at(4)?,
+        }))
+    }
+}
+
+impl_default_fixed_codec_for!(FixedLockReceipt);
+
+pub struct FixedLockReceiptSchema;
+
+impl StoreSchema for FixedLockReceiptSchema {
+    type Key = FixedLockReceiptID;
+    type Value = FixedLockReceipt;
+}
+
+/// Key for `FixedProcessedNonceSchema`: guards `release` against replaying
+/// the same source-chain nonce twice, scoped per asset so two assets'
+/// independent nonce counters can't collide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedNonceID {
+    asset_id: AssetID,
+    nonce: u64,
+}
+
+impl FixedNonceID {
+    pub fn new(asset_id: AssetID, nonce: u64) -> Self {
+        FixedNonceID { asset_id, nonce }
+    }
+}
+
+impl Encodable for FixedNonceID {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(2).append(&self.asset_id).append(&self.nonce);
+    }
+}
+
+impl Decodable for FixedNonceID {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedNonceID {
+            asset_id: r.val_at(0)?,
+            nonce: r.val_at(1)?,
+        })
+    }
+}
+
+/// Marker value for `FixedProcessedNonceSchema`: presence of the key alone
+/// means the nonce has been released.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedProcessedNonce;
+
+impl Encodable for FixedProcessedNonce {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(0);
+    }
+}
+
+impl Decodable for FixedProcessedNonce {
+    fn decode(_r: &Rlp) -> Result<Self, DecoderError> {
+        Ok(FixedProcessedNonce)
+    }
+}
+
+impl_default_fixed_codec_for!(FixedNonceID);
+impl_default_fixed_codec_for!(FixedProcessedNonce);
+
+pub struct FixedProcessedNonceSchema;
+
+impl StoreSchema for FixedProcessedNonceSchema {
+    type Key = FixedNonceID;
+    type Value = FixedProcessedNonce;
+}
+
+/// Key for `FixedSymbolSchema`: a normalized `symbol` string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedSymbol(String);
+
+impl FixedSymbol {
+    pub fn new(symbol: String) -> Self {
+        FixedSymbol(symbol)
+    }
+}
+
+impl Encodable for FixedSymbol {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(1).append(&self.0.as_bytes());
+    }
+}
+
+impl Decodable for FixedSymbol {
+    fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+        let bytes: Vec<u8> = r.val_at(0)?;
+        let symbol =
+            String::from_utf8(bytes).map_err(|_| DecoderError::Custom("invalid utf8 symbol"))?;
+        Ok(FixedSymbol(symbol))
+    }
+}
+
+impl_default_fixed_codec_for!(FixedSymbol);
+
+/// `symbol -> AssetID` name registrar. Populated by `NativeBankContract::register`
+/// so clients can resolve an asset without precomputing `Hash(ChainID + AssetContractAddress)`.
+pub struct FixedSymbolSchema;
+
+impl StoreSchema for FixedSymbolSchema {
+    type Key = FixedSymbol;
+    type Value = FixedAssetID;
+}
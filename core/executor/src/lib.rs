@@ -0,0 +1,3 @@
+pub mod cycles;
+pub mod fixed_types;
+pub mod native_contract;